@@ -1,6 +1,6 @@
 use std::sync::OnceLock;
 
-use crate::config::models::Config;
+use crate::config::models::{Config, PluginConfig};
 
 pub static TRACE_CONTENT_ENABLED: OnceLock<bool> = OnceLock::new();
 
@@ -21,3 +21,61 @@ pub fn load_config(path: &str) -> Result<Config, Box<dyn std::error::Error>> {
 pub fn get_trace_content_enabled() -> bool {
     *TRACE_CONTENT_ENABLED.get_or_init(|| true)
 }
+
+const REDACTED: &str = "<redacted>";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Yaml,
+}
+
+/// Renders the effective configuration for inspection. Provider API keys,
+/// secret-looking provider params and tracing API keys are redacted unless
+/// `include_secrets` is set.
+pub fn export_config(
+    config: &Config,
+    format: ExportFormat,
+    include_secrets: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut config = config.clone();
+    if !include_secrets {
+        redact_secrets(&mut config);
+    }
+
+    let output = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&config)?,
+        ExportFormat::Yaml => serde_yaml::to_string(&config)?,
+    };
+    Ok(output)
+}
+
+fn redact_secrets(config: &mut Config) {
+    for provider in config.providers.iter_mut() {
+        if !provider.api_key.is_empty() {
+            provider.api_key = REDACTED.to_string();
+        }
+        for (key, value) in provider.params.iter_mut() {
+            if is_secret_param(key) {
+                *value = REDACTED.to_string();
+            }
+        }
+    }
+
+    for pipeline in config.pipelines.iter_mut() {
+        for plugin in pipeline.plugins.iter_mut() {
+            if let PluginConfig::Tracing { api_key, .. } = plugin {
+                if !api_key.is_empty() {
+                    *api_key = REDACTED.to_string();
+                }
+            }
+        }
+    }
+}
+
+fn is_secret_param(key: &str) -> bool {
+    let key = key.to_lowercase();
+    ["key", "secret", "token", "password"]
+        .iter()
+        .any(|marker| key.contains(marker))
+}
@@ -0,0 +1,213 @@
+use std::collections::HashSet;
+
+use crate::config::models::{Config, PluginConfig, Provider};
+use crate::providers::registry::{AZURE, BEDROCK, SUPPORTED_PROVIDER_TYPES, VERTEXAI};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintFinding {
+    pub severity: LintSeverity,
+    pub message: String,
+}
+
+impl LintFinding {
+    fn new(severity: LintSeverity, message: String) -> Self {
+        Self { severity, message }
+    }
+}
+
+// Provider params the provider implementations expect when built or unwrap
+// on every request.
+fn required_provider_params(provider: &Provider) -> &'static [&'static str] {
+    match provider.r#type.as_str() {
+        AZURE if provider.params.contains_key("base_url") => &["api_version"],
+        AZURE => &["api_version", "resource_name"],
+        BEDROCK
+            if provider
+                .params
+                .get("use_iam_role")
+                .is_some_and(|v| v.parse::<bool>().unwrap_or(false)) =>
+        {
+            &["region"]
+        }
+        BEDROCK => &["region", "AWS_ACCESS_KEY_ID", "AWS_SECRET_ACCESS_KEY"],
+        VERTEXAI => &["project_id", "location"],
+        _ => &[],
+    }
+}
+
+// Model params the provider implementations unwrap at request time.
+fn required_model_params(provider_type: &str) -> &'static [&'static str] {
+    match provider_type {
+        AZURE => &["deployment"],
+        BEDROCK => &["model_provider"],
+        _ => &[],
+    }
+}
+
+/// Flags common configuration mistakes. Findings are advisory and do not
+/// prevent the configuration from loading.
+pub fn lint_config(config: &Config) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+
+    let mut provider_keys = HashSet::new();
+    for provider in &config.providers {
+        if !provider_keys.insert(provider.key.as_str()) {
+            findings.push(LintFinding::new(
+                LintSeverity::Error,
+                format!("Duplicate provider key '{}'", provider.key),
+            ));
+        }
+        if !SUPPORTED_PROVIDER_TYPES.contains(&provider.r#type.as_str()) {
+            findings.push(LintFinding::new(
+                LintSeverity::Error,
+                format!(
+                    "Provider '{}' has unsupported type '{}'; expected one of: {}",
+                    provider.key,
+                    provider.r#type,
+                    SUPPORTED_PROVIDER_TYPES.join(", ")
+                ),
+            ));
+        }
+        for param in required_provider_params(provider) {
+            if !provider.params.contains_key(*param) {
+                findings.push(LintFinding::new(
+                    LintSeverity::Error,
+                    format!(
+                        "Provider '{}' is missing '{}' required by {} providers",
+                        provider.key, param, provider.r#type
+                    ),
+                ));
+            }
+        }
+        if !config.models.iter().any(|m| m.provider == provider.key) {
+            findings.push(LintFinding::new(
+                LintSeverity::Warning,
+                format!("Provider '{}' is not used by any model", provider.key),
+            ));
+        }
+    }
+
+    let mut model_keys = HashSet::new();
+    // Models ModelRegistry::new will actually load.
+    let mut routable_model_keys = HashSet::new();
+    for model in &config.models {
+        if !model_keys.insert(model.key.as_str()) {
+            findings.push(LintFinding::new(
+                LintSeverity::Error,
+                format!("Duplicate model key '{}'", model.key),
+            ));
+        }
+        match config.providers.iter().find(|p| p.key == model.provider) {
+            Some(provider) => {
+                if SUPPORTED_PROVIDER_TYPES.contains(&provider.r#type.as_str()) {
+                    routable_model_keys.insert(model.key.as_str());
+                }
+                for param in required_model_params(&provider.r#type) {
+                    if !model.params.contains_key(*param) {
+                        findings.push(LintFinding::new(
+                            LintSeverity::Error,
+                            format!(
+                                "Model '{}' is missing '{}' required by {} providers",
+                                model.key, param, provider.r#type
+                            ),
+                        ));
+                    }
+                }
+            }
+            None => findings.push(LintFinding::new(
+                LintSeverity::Error,
+                format!(
+                    "Model '{}' references unknown provider '{}'",
+                    model.key, model.provider
+                ),
+            )),
+        }
+    }
+
+    let mut pipeline_names = HashSet::new();
+    for pipeline in &config.pipelines {
+        if !pipeline_names.insert(pipeline.name.as_str()) {
+            findings.push(LintFinding::new(
+                LintSeverity::Error,
+                format!("Duplicate pipeline name '{}'", pipeline.name),
+            ));
+        }
+        if pipeline.plugins.is_empty() {
+            findings.push(LintFinding::new(
+                LintSeverity::Warning,
+                format!("Pipeline '{}' has no plugins", pipeline.name),
+            ));
+            continue;
+        }
+
+        let mut router_count = 0;
+        for plugin in &pipeline.plugins {
+            if let PluginConfig::ModelRouter { models } = plugin {
+                router_count += 1;
+                if models.is_empty() {
+                    findings.push(LintFinding::new(
+                        LintSeverity::Warning,
+                        format!(
+                            "Pipeline '{}' has a model router with no models",
+                            pipeline.name
+                        ),
+                    ));
+                }
+                for model in models {
+                    if !model_keys.contains(model.as_str()) {
+                        findings.push(LintFinding::new(
+                            LintSeverity::Error,
+                            format!(
+                                "Pipeline '{}' routes to unknown model '{}'",
+                                pipeline.name, model
+                            ),
+                        ));
+                    } else if !routable_model_keys.contains(model.as_str()) {
+                        findings.push(LintFinding::new(
+                            LintSeverity::Error,
+                            format!(
+                                "Pipeline '{}' routes to model '{}' whose provider cannot be loaded",
+                                pipeline.name, model
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+        match router_count {
+            0 => findings.push(LintFinding::new(
+                LintSeverity::Info,
+                format!(
+                    "Pipeline '{}' has no model router and serves no routes",
+                    pipeline.name
+                ),
+            )),
+            1 => {}
+            // Each router registers the same route, which axum rejects at startup.
+            count => findings.push(LintFinding::new(
+                LintSeverity::Error,
+                format!(
+                    "Pipeline '{}' has {} model routers; only one is allowed",
+                    pipeline.name, count
+                ),
+            )),
+        }
+    }
+
+    if !config.pipelines.is_empty() && !pipeline_names.contains("default") {
+        findings.push(LintFinding::new(
+            LintSeverity::Info,
+            "No pipeline named 'default'; requests without a pipeline header use the first one"
+                .to_string(),
+        ));
+    }
+
+    findings
+}
@@ -1,3 +1,6 @@
 pub mod constants;
 pub mod lib;
+pub mod lint;
 pub mod models;
+#[cfg(test)]
+mod tests;
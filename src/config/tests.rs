@@ -0,0 +1,493 @@
+use std::collections::HashMap;
+
+use super::lib::{export_config, ExportFormat};
+use super::lint::{lint_config, LintFinding, LintSeverity};
+use super::models::{Config, ModelConfig, Pipeline, PipelineType, PluginConfig, Provider};
+
+fn test_config() -> Config {
+    Config {
+        general: None,
+        providers: vec![Provider {
+            key: "bedrock".to_string(),
+            r#type: "bedrock".to_string(),
+            api_key: "sk-provider-secret".to_string(),
+            params: HashMap::from([
+                ("region".to_string(), "us-east-1".to_string()),
+                (
+                    "AWS_SECRET_ACCESS_KEY".to_string(),
+                    "aws-secret-value".to_string(),
+                ),
+            ]),
+        }],
+        models: vec![],
+        pipelines: vec![Pipeline {
+            name: "default".to_string(),
+            r#type: PipelineType::Chat,
+            plugins: vec![PluginConfig::Tracing {
+                endpoint: "https://api.traceloop.com/v1/traces".to_string(),
+                api_key: "tl-tracing-secret".to_string(),
+            }],
+        }],
+    }
+}
+
+fn assert_redacted(exported: &Config) {
+    assert_eq!(exported.providers[0].api_key, "<redacted>");
+    assert_eq!(
+        exported.providers[0].params["AWS_SECRET_ACCESS_KEY"],
+        "<redacted>"
+    );
+    match &exported.pipelines[0].plugins[0] {
+        PluginConfig::Tracing { api_key, .. } => assert_eq!(api_key, "<redacted>"),
+        plugin => panic!("unexpected plugin: {:?}", plugin),
+    }
+}
+
+#[test]
+fn test_export_config_json_redacts_secrets_by_default() {
+    let output = export_config(&test_config(), ExportFormat::Json, false).unwrap();
+
+    let exported: Config = serde_json::from_str(&output).unwrap();
+    assert_eq!(exported.providers[0].params["region"], "us-east-1");
+    assert_redacted(&exported);
+    assert!(!output.contains("sk-provider-secret"));
+    assert!(!output.contains("aws-secret-value"));
+    assert!(!output.contains("tl-tracing-secret"));
+}
+
+#[test]
+fn test_export_config_yaml_redacts_secrets_by_default() {
+    let output = export_config(&test_config(), ExportFormat::Yaml, false).unwrap();
+
+    let exported: Config = serde_yaml::from_str(&output).unwrap();
+    assert_eq!(exported.pipelines[0].name, "default");
+    assert_redacted(&exported);
+    assert!(!output.contains("sk-provider-secret"));
+    assert!(!output.contains("aws-secret-value"));
+    assert!(!output.contains("tl-tracing-secret"));
+}
+
+#[test]
+fn test_export_config_includes_secrets_when_requested() {
+    let output = export_config(&test_config(), ExportFormat::Json, true).unwrap();
+
+    let exported: Config = serde_json::from_str(&output).unwrap();
+    assert_eq!(exported.providers[0].api_key, "sk-provider-secret");
+    assert_eq!(
+        exported.providers[0].params["AWS_SECRET_ACCESS_KEY"],
+        "aws-secret-value"
+    );
+}
+
+#[test]
+fn test_export_config_leaves_empty_api_keys_empty() {
+    let mut config = test_config();
+    config.providers[0].api_key = String::new();
+    config.pipelines[0].plugins = vec![PluginConfig::Tracing {
+        endpoint: "https://api.traceloop.com/v1/traces".to_string(),
+        api_key: String::new(),
+    }];
+
+    let output = export_config(&config, ExportFormat::Json, false).unwrap();
+
+    let exported: Config = serde_json::from_str(&output).unwrap();
+    assert_eq!(exported.providers[0].api_key, "");
+    match &exported.pipelines[0].plugins[0] {
+        PluginConfig::Tracing { api_key, .. } => assert_eq!(api_key, ""),
+        plugin => panic!("unexpected plugin: {:?}", plugin),
+    }
+}
+
+fn lint_test_config() -> Config {
+    Config {
+        general: None,
+        providers: vec![
+            Provider {
+                key: "openai".to_string(),
+                r#type: "openai".to_string(),
+                ..Default::default()
+            },
+            Provider {
+                key: "unused".to_string(),
+                r#type: "anthropic".to_string(),
+                ..Default::default()
+            },
+        ],
+        models: vec![ModelConfig {
+            key: "gpt-4".to_string(),
+            r#type: "gpt-4".to_string(),
+            provider: "openai".to_string(),
+            params: HashMap::new(),
+        }],
+        pipelines: vec![
+            Pipeline {
+                name: "default".to_string(),
+                r#type: PipelineType::Chat,
+                plugins: vec![PluginConfig::ModelRouter {
+                    models: vec!["gpt-4".to_string()],
+                }],
+            },
+            Pipeline {
+                name: "empty".to_string(),
+                r#type: PipelineType::Completion,
+                plugins: vec![],
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_lint_config_flags_unused_provider() {
+    let findings = lint_config(&lint_test_config());
+
+    let finding = findings
+        .iter()
+        .find(|f| f.message.contains("'unused'"))
+        .expect("unused provider should be flagged");
+    assert_eq!(finding.severity, LintSeverity::Warning);
+    assert!(!findings.iter().any(|f| f.message.contains("'openai'")));
+}
+
+#[test]
+fn test_lint_config_flags_empty_pipeline() {
+    let findings = lint_config(&lint_test_config());
+
+    let finding = findings
+        .iter()
+        .find(|f| f.message.contains("'empty'"))
+        .expect("empty pipeline should be flagged");
+    assert_eq!(finding.severity, LintSeverity::Warning);
+    assert_eq!(finding.message, "Pipeline 'empty' has no plugins");
+    assert!(!findings.iter().any(|f| f.message.contains("'default'")));
+}
+
+fn assert_finding(findings: &[LintFinding], severity: LintSeverity, message: &str) {
+    let finding = findings
+        .iter()
+        .find(|f| f.message == message)
+        .unwrap_or_else(|| panic!("missing finding '{}' in {:?}", message, findings));
+    assert_eq!(finding.severity, severity);
+}
+
+fn provider(key: &str, r#type: &str, params: &[(&str, &str)]) -> Provider {
+    Provider {
+        key: key.to_string(),
+        r#type: r#type.to_string(),
+        params: params
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn model(key: &str, provider: &str, params: &[(&str, &str)]) -> ModelConfig {
+    ModelConfig {
+        key: key.to_string(),
+        r#type: key.to_string(),
+        provider: provider.to_string(),
+        params: params
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_lint_config_flags_unsupported_provider_type() {
+    let mut config = lint_test_config();
+    config.providers[1].r#type = "vertex_ai".to_string();
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Provider 'unused' has unsupported type 'vertex_ai'; expected one of: openai, anthropic, azure, bedrock, vertexai",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_missing_vertexai_provider_params() {
+    let mut config = lint_test_config();
+    config.providers.push(provider(
+        "vertex",
+        "vertexai",
+        &[("project_id", "my-project")],
+    ));
+
+    let findings = lint_config(&config);
+
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Provider 'vertex' is missing 'location' required by vertexai providers",
+    );
+    assert!(!findings.iter().any(|f| f.message.contains("'project_id'")));
+}
+
+#[test]
+fn test_lint_config_flags_missing_bedrock_region() {
+    let mut config = lint_test_config();
+    config.providers.push(provider("aws", "bedrock", &[]));
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Provider 'aws' is missing 'region' required by bedrock providers",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_missing_bedrock_static_credentials() {
+    let mut config = lint_test_config();
+    config
+        .providers
+        .push(provider("aws", "bedrock", &[("region", "us-east-1")]));
+
+    let findings = lint_config(&config);
+
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Provider 'aws' is missing 'AWS_ACCESS_KEY_ID' required by bedrock providers",
+    );
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Provider 'aws' is missing 'AWS_SECRET_ACCESS_KEY' required by bedrock providers",
+    );
+}
+
+#[test]
+fn test_lint_config_allows_bedrock_iam_role_without_static_credentials() {
+    let mut config = lint_test_config();
+    config.providers.push(provider(
+        "aws",
+        "bedrock",
+        &[("region", "us-east-1"), ("use_iam_role", "true")],
+    ));
+
+    let findings = lint_config(&config);
+
+    assert!(!findings
+        .iter()
+        .any(|f| f.message.starts_with("Provider 'aws' is missing")));
+}
+
+#[test]
+fn test_lint_config_flags_missing_azure_provider_params() {
+    let mut config = lint_test_config();
+    config
+        .providers
+        .push(provider("azure-openai", "azure", &[]));
+
+    let findings = lint_config(&config);
+
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Provider 'azure-openai' is missing 'api_version' required by azure providers",
+    );
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Provider 'azure-openai' is missing 'resource_name' required by azure providers",
+    );
+}
+
+#[test]
+fn test_lint_config_allows_azure_base_url_without_resource_name() {
+    let mut config = lint_test_config();
+    config.providers.push(provider(
+        "azure-openai",
+        "azure",
+        &[
+            ("api_version", "2024-02-01"),
+            ("base_url", "https://proxy.example.com"),
+        ],
+    ));
+
+    let findings = lint_config(&config);
+
+    assert!(!findings
+        .iter()
+        .any(|f| f.message.contains("'resource_name'")));
+}
+
+#[test]
+fn test_lint_config_flags_duplicate_provider_key() {
+    let mut config = lint_test_config();
+    config.providers.push(provider("openai", "openai", &[]));
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Duplicate provider key 'openai'",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_duplicate_model_key() {
+    let mut config = lint_test_config();
+    config.models.push(model("gpt-4", "openai", &[]));
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Duplicate model key 'gpt-4'",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_duplicate_pipeline_name() {
+    let mut config = lint_test_config();
+    config.pipelines[1].name = "default".to_string();
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Duplicate pipeline name 'default'",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_model_with_unknown_provider() {
+    let mut config = lint_test_config();
+    config.models.push(model("claude", "missing", &[]));
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Model 'claude' references unknown provider 'missing'",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_router_with_unknown_model() {
+    let mut config = lint_test_config();
+    config.pipelines[0].plugins = vec![PluginConfig::ModelRouter {
+        models: vec!["gpt-4".to_string(), "gpt-5".to_string()],
+    }];
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Pipeline 'default' routes to unknown model 'gpt-5'",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_router_with_unloadable_model() {
+    let mut config = lint_test_config();
+    config.providers.push(provider("vertex", "vertex_ai", &[]));
+    config.models.push(model("gemini", "vertex", &[]));
+    config.models.push(model("orphan", "missing", &[]));
+    config.pipelines[0].plugins = vec![PluginConfig::ModelRouter {
+        models: vec![
+            "gpt-4".to_string(),
+            "gemini".to_string(),
+            "orphan".to_string(),
+        ],
+    }];
+
+    let findings = lint_config(&config);
+
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Pipeline 'default' routes to model 'gemini' whose provider cannot be loaded",
+    );
+    assert_finding(
+        &findings,
+        LintSeverity::Error,
+        "Pipeline 'default' routes to model 'orphan' whose provider cannot be loaded",
+    );
+    assert!(!findings.iter().any(|f| f.message.contains("'gpt-4'")));
+}
+
+#[test]
+fn test_lint_config_flags_router_with_no_models() {
+    let mut config = lint_test_config();
+    config.pipelines[0].plugins = vec![PluginConfig::ModelRouter { models: vec![] }];
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Warning,
+        "Pipeline 'default' has a model router with no models",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_azure_model_missing_deployment() {
+    let mut config = lint_test_config();
+    config.providers.push(provider(
+        "azure-openai",
+        "azure",
+        &[("api_version", "2024-02-01"), ("resource_name", "res")],
+    ));
+    config
+        .models
+        .push(model("gpt-4-azure", "azure-openai", &[]));
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Model 'gpt-4-azure' is missing 'deployment' required by azure providers",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_bedrock_model_missing_model_provider() {
+    let mut config = lint_test_config();
+    config.providers.push(provider(
+        "aws",
+        "bedrock",
+        &[("region", "us-east-1"), ("use_iam_role", "true")],
+    ));
+    config.models.push(model("titan", "aws", &[]));
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Model 'titan' is missing 'model_provider' required by bedrock providers",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_multiple_routers_in_pipeline() {
+    let mut config = lint_test_config();
+    config.pipelines[0].plugins.push(PluginConfig::ModelRouter {
+        models: vec!["gpt-4".to_string()],
+    });
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Error,
+        "Pipeline 'default' has 2 model routers; only one is allowed",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_pipeline_without_router() {
+    let mut config = lint_test_config();
+    config.pipelines[1].plugins = vec![PluginConfig::Logging {
+        level: "info".to_string(),
+    }];
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Info,
+        "Pipeline 'empty' has no model router and serves no routes",
+    );
+}
+
+#[test]
+fn test_lint_config_flags_missing_default_pipeline() {
+    let mut config = lint_test_config();
+    config.pipelines[0].name = "chat".to_string();
+
+    assert_finding(
+        &lint_config(&config),
+        LintSeverity::Info,
+        "No pipeline named 'default'; requests without a pipeline header use the first one",
+    );
+}
@@ -8,6 +8,16 @@ use crate::providers::{
     openai::OpenAIProvider, provider::Provider, vertexai::VertexAIProvider,
 };
 
+pub const OPENAI: &str = "openai";
+pub const ANTHROPIC: &str = "anthropic";
+pub const AZURE: &str = "azure";
+pub const BEDROCK: &str = "bedrock";
+pub const VERTEXAI: &str = "vertexai";
+
+/// Provider types `ProviderRegistry::new` knows how to build. Providers of any
+/// other type are skipped.
+pub const SUPPORTED_PROVIDER_TYPES: &[&str] = &[OPENAI, ANTHROPIC, AZURE, BEDROCK, VERTEXAI];
+
 pub struct ProviderRegistry {
     providers: HashMap<String, Arc<dyn Provider>>,
 }
@@ -18,11 +28,11 @@ impl ProviderRegistry {
 
         for config in provider_configs {
             let provider: Arc<dyn Provider> = match config.r#type.as_str() {
-                "openai" => Arc::new(OpenAIProvider::new(config)),
-                "anthropic" => Arc::new(AnthropicProvider::new(config)),
-                "azure" => Arc::new(AzureProvider::new(config)),
-                "bedrock" => Arc::new(BedrockProvider::new(config)),
-                "vertexai" => Arc::new(VertexAIProvider::new(config)),
+                OPENAI => Arc::new(OpenAIProvider::new(config)),
+                ANTHROPIC => Arc::new(AnthropicProvider::new(config)),
+                AZURE => Arc::new(AzureProvider::new(config)),
+                BEDROCK => Arc::new(BedrockProvider::new(config)),
+                VERTEXAI => Arc::new(VertexAIProvider::new(config)),
                 _ => continue,
             };
             providers.insert(config.key.clone(), provider);